@@ -0,0 +1,61 @@
+//! Diagnostics produced while evaluating and executing a source file.
+
+use std::collections::BTreeSet;
+
+use crate::syntax::Span;
+
+/// A single diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Diag {
+    /// The span this diagnostic points to.
+    pub span: Span,
+    /// The human-readable message.
+    pub message: String,
+}
+
+impl Diag {
+    /// Create a new diagnostic.
+    pub fn new(span: Span, message: impl Into<String>) -> Self {
+        Self { span, message: message.into() }
+    }
+}
+
+/// An accumulated set of diagnostics.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DiagSet(BTreeSet<Diag>);
+
+impl DiagSet {
+    /// Create an empty set of diagnostics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a diagnostic into the set.
+    pub fn insert(&mut self, diag: Diag) {
+        self.0.insert(diag);
+    }
+}
+
+/// A value bundled with the diagnostics produced while computing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pass<T> {
+    /// The output value.
+    pub output: T,
+    /// The diagnostics accumulated along the way.
+    pub diags: DiagSet,
+}
+
+impl<T> Pass<T> {
+    /// Create a new pass from an output value and diagnostics.
+    pub fn new(output: T, diags: DiagSet) -> Self {
+        Self { output, diags }
+    }
+}
+
+/// Construct a [`Diag`] at the given span.
+#[macro_export]
+macro_rules! error {
+    ($span:expr, $($tts:tt)*) => {
+        $crate::diag::Diag::new($span, format!($($tts)*))
+    };
+}
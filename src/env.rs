@@ -0,0 +1,12 @@
+//! The environment that execution draws external resources from.
+
+/// Access to fonts, images and other resources needed during execution.
+#[derive(Default)]
+pub struct Env {}
+
+impl Env {
+    /// Create a new, empty environment.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
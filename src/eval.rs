@@ -0,0 +1,22 @@
+//! Evaluated values produced by the interpreter.
+
+use std::rc::Rc;
+
+use crate::exec::{Exec, ExecContext};
+
+/// An evaluated template, ready to be executed onto an [`ExecContext`].
+#[derive(Clone)]
+pub struct TemplateValue(Rc<dyn Fn(&mut ExecContext)>);
+
+impl TemplateValue {
+    /// Wrap a closure as a template value.
+    pub fn new(f: impl Fn(&mut ExecContext) + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+}
+
+impl Exec for TemplateValue {
+    fn exec(&self, ctx: &mut ExecContext) {
+        (self.0)(ctx)
+    }
+}
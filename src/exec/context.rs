@@ -6,10 +6,12 @@ use crate::env::Env;
 use crate::eval::TemplateValue;
 use crate::geom::{Align, Dir, Gen, GenAxis, Length, Linear, Sides, Size};
 use crate::layout::{
-    AnyNode, PadNode, PageRun, ParChild, ParNode, StackChild, StackNode, TextNode, Tree,
+    AnyNode, ColumnNode, PadNode, PageRun, ParChild, ParNode, StackChild, StackNode, TextNode,
+    Tree,
 };
 use crate::parse::{is_newline, Scanner};
 use crate::syntax::Span;
+use unicode_bidi::{bidi_class, BidiClass, BidiInfo, Level};
 
 /// The context for execution.
 pub struct ExecContext<'a> {
@@ -54,9 +56,23 @@ impl<'a> ExecContext<'a> {
 
     /// Execute a template and return the result as a stack node.
     pub fn exec_group(&mut self, template: &TemplateValue) -> StackNode {
+        self.exec_group_with_dirs(template, Dir::TTB, self.state.lang.dir)
+    }
+
+    /// Execute a template and return the result as a stack node, stacking
+    /// children along `main` with the cross axis flowing in `cross`.
+    pub fn exec_group_with_dirs(
+        &mut self,
+        template: &TemplateValue,
+        main: Dir,
+        cross: Dir,
+    ) -> StackNode {
         let snapshot = self.state.clone();
         let page = self.page.take();
-        let stack = mem::replace(&mut self.stack, StackBuilder::new(&self.state));
+        let stack = mem::replace(
+            &mut self.stack,
+            StackBuilder::with_dirs(&self.state, Gen::new(main, cross)),
+        );
 
         template.exec(self);
 
@@ -65,6 +81,17 @@ impl<'a> ExecContext<'a> {
         mem::replace(&mut self.stack, stack).build()
     }
 
+    /// Execute a template into its own stack and push the result as a single,
+    /// unbreakable block-level child of the active stack. The page/region
+    /// layout must place such a child as a whole or move it to the next
+    /// page/region rather than splitting it across the boundary.
+    pub fn exec_keep_together(&mut self, template: &TemplateValue) {
+        let node = self.exec_group(template);
+        let aligns = self.state.aligns;
+        self.stack.parbreak(&self.state);
+        self.stack.push_hard(StackChild::Any(node.into(), aligns, true));
+    }
+
     /// Push any node into the active paragraph.
     pub fn push(&mut self, node: impl Into<AnyNode>) {
         let align = self.state.aligns.cross;
@@ -145,6 +172,8 @@ impl<'a> ExecContext<'a> {
 struct PageBuilder {
     size: Size,
     padding: Sides<Linear>,
+    columns: usize,
+    column_gutter: Linear,
     hard: bool,
 }
 
@@ -153,15 +182,22 @@ impl PageBuilder {
         Self {
             size: state.page.size,
             padding: state.page.margins(),
+            columns: state.page.columns,
+            column_gutter: state.page.column_gutter,
             hard,
         }
     }
 
     fn build(self, child: StackNode, keep: bool) -> Option<PageRun> {
-        let Self { size, padding, hard } = self;
-        (!child.children.is_empty() || (keep && hard)).then(|| PageRun {
-            size,
-            child: PadNode { padding, child: child.into() }.into(),
+        let Self { size, padding, columns, column_gutter, hard } = self;
+        (!child.children.is_empty() || (keep && hard)).then(|| {
+            let child: AnyNode = if columns > 1 {
+                ColumnNode { count: columns, gutter: column_gutter, child: child.into() }.into()
+            } else {
+                child.into()
+            };
+
+            PageRun { size, child: PadNode { padding, child }.into() }
         })
     }
 }
@@ -175,8 +211,12 @@ struct StackBuilder {
 
 impl StackBuilder {
     fn new(state: &State) -> Self {
+        Self::with_dirs(state, Gen::new(Dir::TTB, state.lang.dir))
+    }
+
+    fn with_dirs(state: &State, dirs: Gen<Dir>) -> Self {
         Self {
-            dirs: Gen::new(Dir::TTB, state.lang.dir),
+            dirs,
             children: vec![],
             last: Last::None,
             par: ParBuilder::new(state),
@@ -242,13 +282,21 @@ impl ParBuilder {
         let props = state.font.resolve_props();
 
         if let Some(ParChild::Text(prev, prev_align)) = self.children.last_mut() {
-            if *prev_align == align && prev.props == props {
+            let crosses_dir_boundary = prev
+                .text
+                .chars()
+                .rev()
+                .find_map(strong_dir)
+                .zip(text.chars().find_map(strong_dir))
+                .is_some_and(|(a, b)| a != b);
+
+            if *prev_align == align && prev.props == props && !crosses_dir_boundary {
                 prev.text.push_str(&text);
                 return;
             }
         }
 
-        self.children.push(ParChild::Text(TextNode { text, props }, align));
+        self.children.push(ParChild::Text(TextNode { text, props, dir: self.dir }, align));
     }
 
     fn push_soft(&mut self, child: ParChild) {
@@ -261,14 +309,90 @@ impl ParBuilder {
     }
 
     fn build(self) -> Option<StackChild> {
-        let Self { aligns, dir, line_spacing, children, .. } = self;
+        let Self { aligns, dir, line_spacing, mut children, .. } = self;
         (!children.is_empty()).then(|| {
+            reorder_bidi(&mut children, dir);
             let node = ParNode { dir, line_spacing, children };
-            StackChild::Any(node.into(), aligns)
+            StackChild::Any(node.into(), aligns, false)
         })
     }
 }
 
+/// Run the Unicode Bidirectional Algorithm over `children` and reorder them
+/// into visual order line by line (lines end at forced line breaks), tagging
+/// each text run with its resolved direction.
+///
+/// Non-text children are treated as neutral, opaque objects (like an
+/// embedded image) for the purposes of level resolution.
+fn reorder_bidi(children: &mut Vec<ParChild>, dir: Dir) {
+    let mut text = String::new();
+    // The byte offset of each child's first character in `text`, or `None`
+    // for a child that contributes no characters (e.g. a trailing empty
+    // text run left behind when source text ends in a newline).
+    let mut starts = Vec::with_capacity(children.len());
+    for child in children.iter() {
+        let start = text.len();
+        let empty = match child {
+            ParChild::Text(node, _) => {
+                text.push_str(&node.text);
+                node.text.is_empty()
+            }
+            _ => {
+                text.push('\u{FFFC}');
+                false
+            }
+        };
+        starts.push((!empty).then_some(start));
+    }
+
+    let base_level = if dir == Dir::RTL { Level::rtl() } else { Level::ltr() };
+    let info = BidiInfo::new(&text, Some(base_level));
+    let mut last_level = base_level;
+    let levels: Vec<Level> = starts
+        .iter()
+        .map(|&start| {
+            let level = start.map_or(last_level, |start| info.levels[start]);
+            last_level = level;
+            level
+        })
+        .collect();
+
+    // Reorder each line on its own, so that no child moves across a forced
+    // line break.
+    let mut order = Vec::with_capacity(levels.len());
+    let mut line_start = 0;
+    for end in 0..=levels.len() {
+        if end == levels.len() || matches!(children[end], ParChild::Linebreak) {
+            if line_start < end {
+                let line = BidiInfo::reorder_visual(&levels[line_start..end]);
+                order.extend(line.into_iter().map(|index| line_start + index));
+            }
+            order.extend((end < levels.len()).then_some(end));
+            line_start = end + 1;
+        }
+    }
+
+    let old = mem::take(children);
+    let mut old = old.into_iter().map(Some).collect::<Vec<_>>();
+
+    for &index in &order {
+        let mut child = old[index].take().unwrap();
+        if let ParChild::Text(node, _) = &mut child {
+            node.dir = if levels[index].is_rtl() { Dir::RTL } else { Dir::LTR };
+        }
+        children.push(child);
+    }
+}
+
+/// The strong (non-neutral) direction of a character, if any.
+fn strong_dir(c: char) -> Option<Dir> {
+    match bidi_class(c) {
+        BidiClass::L => Some(Dir::LTR),
+        BidiClass::R | BidiClass::AL => Some(Dir::RTL),
+        _ => None,
+    }
+}
+
 /// Finite state machine for spacing coalescing.
 enum Last<N> {
     None,
@@ -293,4 +417,98 @@ impl<N> Last<N> {
     fn hard(&mut self) {
         *self = Self::None;
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::FontProps;
+
+    #[test]
+    fn exec_group_with_dirs_sets_the_requested_axes_and_spacing_lands_between_children() {
+        let mut env = Env::new();
+        let mut ctx = ExecContext::new(&mut env, State::default());
+
+        let template = TemplateValue::new(|ctx| {
+            ctx.push_text("a");
+            ctx.push_spacing(GenAxis::Main, Length::pt(5.0));
+            ctx.push_text("b");
+        });
+
+        let stack = ctx.exec_group_with_dirs(&template, Dir::LTR, Dir::RTL);
+
+        assert_eq!(stack.dirs, Gen::new(Dir::LTR, Dir::RTL));
+        assert!(stack
+            .children
+            .iter()
+            .any(|child| matches!(child, StackChild::Spacing(amount) if *amount == Length::pt(5.0))));
+        assert!(matches!(
+            stack.children.as_slice(),
+            [StackChild::Any(..), StackChild::Spacing(_), StackChild::Any(..)]
+        ));
+    }
+
+    fn text_children(text: &str) -> Vec<ParChild> {
+        let mut env = Env::new();
+        let mut ctx = ExecContext::new(&mut env, State::default());
+        ctx.push_text(text);
+        let Pass { output: tree, .. } = ctx.finish();
+        let run = tree.runs.into_iter().next().expect("a page was produced");
+        let AnyNode::Pad(pad) = run.child else { panic!("expected a PadNode") };
+        let AnyNode::Stack(stack) = pad.child else { panic!("expected a StackNode") };
+        stack
+            .children
+            .into_iter()
+            .flat_map(|child| match child {
+                StackChild::Any(AnyNode::Par(par), ..) => par.children,
+                _ => vec![],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn trailing_newline_does_not_panic_in_bidi_reorder() {
+        // A trailing `\n` leaves an empty `ParChild::Text` behind; this must
+        // not panic when the paragraph is reordered at `build()` time.
+        let children = text_children("Hello\n");
+        assert!(matches!(children.last(), Some(ParChild::Text(node, _)) if node.text.is_empty()));
+    }
+
+    #[test]
+    fn reorders_rtl_run_within_ltr_paragraph() {
+        let mut children = vec![
+            ParChild::Text(
+                TextNode {
+                    text: "a".into(),
+                    props: FontProps { size: Length::ZERO, strong: false, emph: false },
+                    dir: Dir::LTR,
+                },
+                Align::Left,
+            ),
+            ParChild::Text(
+                TextNode {
+                    text: "א".into(),
+                    props: FontProps { size: Length::ZERO, strong: false, emph: false },
+                    dir: Dir::LTR,
+                },
+                Align::Left,
+            ),
+        ];
+
+        reorder_bidi(&mut children, Dir::LTR);
+
+        let ParChild::Text(hebrew, _) = &children[1] else { panic!("expected text") };
+        assert_eq!(hebrew.text, "א");
+        assert_eq!(hebrew.dir, Dir::RTL);
+    }
+
+    #[test]
+    fn push_text_does_not_merge_across_a_direction_boundary() {
+        let state = State::default();
+        let mut par = ParBuilder::new(&state);
+        par.push_text("hello".into(), &state);
+        par.push_text("שלום".into(), &state);
+
+        assert_eq!(par.children.len(), 2);
+    }
 }
\ No newline at end of file
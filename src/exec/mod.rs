@@ -0,0 +1,13 @@
+//! Execution turns the evaluated document into a layout tree.
+
+mod context;
+mod state;
+
+pub use context::ExecContext;
+pub use state::{FontFamily, State};
+
+/// A value that can be executed onto an [`ExecContext`].
+pub trait Exec {
+    /// Execute this value, pushing its content into `ctx`.
+    fn exec(&self, ctx: &mut ExecContext);
+}
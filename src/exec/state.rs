@@ -0,0 +1,155 @@
+//! Execution state that is threaded through and snapshotted around scopes.
+
+use crate::geom::{Align, Dir, Gen, Length, Linear, Sides, Size};
+use crate::layout::FontProps;
+
+/// The active execution state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct State {
+    /// Page-level state.
+    pub page: PageState,
+    /// Font-level state.
+    pub font: FontState,
+    /// Paragraph-level state.
+    pub par: ParState,
+    /// Language-level state.
+    pub lang: LangState,
+    /// The current alignment.
+    pub aligns: Gen<Align>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            page: PageState::default(),
+            font: FontState::default(),
+            par: ParState::default(),
+            lang: LangState::default(),
+            aligns: Gen::new(Align::Left, Align::Top),
+        }
+    }
+}
+
+/// The size, margins and column configuration of the page being built.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageState {
+    /// The size of the page.
+    pub size: Size,
+    /// The margins around the page content.
+    pub margins: Sides<Linear>,
+    /// The number of columns to split the page content into.
+    pub columns: usize,
+    /// The spacing between adjacent columns.
+    pub column_gutter: Linear,
+}
+
+impl PageState {
+    /// The margins around the page content, unresolved.
+    pub fn margins(&self) -> Sides<Linear> {
+        self.margins
+    }
+}
+
+impl Default for PageState {
+    fn default() -> Self {
+        Self {
+            size: Size::new(Length::pt(595.0), Length::pt(842.0)),
+            margins: Sides::default(),
+            columns: 1,
+            column_gutter: Linear::abs(Length::pt(18.0)),
+        }
+    }
+}
+
+/// A prioritized list of font families to search for a glyph.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FamilyList {
+    /// The families, in descending priority.
+    pub list: Vec<FontFamily>,
+}
+
+/// A single named or generic font family.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FontFamily {
+    /// A font family referenced by name.
+    Named(String),
+    /// The platform's default monospace family.
+    Monospace,
+}
+
+/// The currently active font settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontState {
+    /// The families to search for a glyph, most specific first.
+    pub families: FamilyList,
+    /// The font size, relative to the document's base size.
+    pub size: Linear,
+    /// Whether text is currently set bold.
+    pub strong: bool,
+    /// Whether text is currently set italic.
+    pub emph: bool,
+}
+
+impl FontState {
+    /// Mutably access the family list.
+    pub fn families_mut(&mut self) -> &mut FamilyList {
+        &mut self.families
+    }
+
+    /// Resolve the current font size against the document's base size.
+    pub fn resolve_size(&self) -> Length {
+        self.size.resolve(Self::BASE_SIZE)
+    }
+
+    /// Resolve the current font properties for a run of text.
+    pub fn resolve_props(&self) -> FontProps {
+        FontProps { size: self.resolve_size(), strong: self.strong, emph: self.emph }
+    }
+
+    const BASE_SIZE: Length = Length::pt(11.0);
+}
+
+impl Default for FontState {
+    fn default() -> Self {
+        Self {
+            families: FamilyList::default(),
+            size: Linear::rel(1.0),
+            strong: false,
+            emph: false,
+        }
+    }
+}
+
+/// Paragraph-level spacing configuration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParState {
+    /// The spacing between words.
+    pub word_spacing: Linear,
+    /// The spacing between paragraphs.
+    pub spacing: Linear,
+    /// The spacing between lines.
+    pub leading: Linear,
+}
+
+impl Default for ParState {
+    fn default() -> Self {
+        Self {
+            word_spacing: Linear::rel(0.25),
+            spacing: Linear::rel(1.2),
+            leading: Linear::rel(0.65),
+        }
+    }
+}
+
+/// Settings that depend on the active language and script.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LangState {
+    /// The dominant text direction.
+    pub dir: Dir,
+}
+
+impl Default for LangState {
+    fn default() -> Self {
+        Self { dir: Dir::LTR }
+    }
+}
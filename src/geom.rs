@@ -0,0 +1,147 @@
+//! Geometric primitives shared across the layout and execution stages.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+/// An absolute length.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Length {
+    /// The length in raw points.
+    pub points: f64,
+}
+
+impl Length {
+    /// The zero length.
+    pub const ZERO: Self = Self { points: 0.0 };
+
+    /// Create a length from a point value.
+    pub const fn pt(points: f64) -> Self {
+        Self { points }
+    }
+}
+
+impl Add for Length {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::pt(self.points + other.points)
+    }
+}
+
+impl Sub for Length {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::pt(self.points - other.points)
+    }
+}
+
+impl Mul<f64> for Length {
+    type Output = Self;
+    fn mul(self, scale: f64) -> Self {
+        Self::pt(self.points * scale)
+    }
+}
+
+impl Div<f64> for Length {
+    type Output = Self;
+    fn div(self, scale: f64) -> Self {
+        Self::pt(self.points / scale)
+    }
+}
+
+/// A length relative to some base length (e.g. the current font size).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Linear {
+    /// The relative part, as a multiple of the base length.
+    pub rel: f64,
+    /// The absolute part.
+    pub abs: Length,
+}
+
+impl Linear {
+    /// A purely relative linear value.
+    pub const fn rel(rel: f64) -> Self {
+        Self { rel, abs: Length::ZERO }
+    }
+
+    /// A purely absolute linear value.
+    pub const fn abs(abs: Length) -> Self {
+        Self { rel: 0.0, abs }
+    }
+
+    /// Resolve this value against a base length.
+    pub fn resolve(self, base: Length) -> Length {
+        base * self.rel + self.abs
+    }
+}
+
+/// A size with a width and a height.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Size {
+    /// The width.
+    pub width: Length,
+    /// The height.
+    pub height: Length,
+}
+
+impl Size {
+    /// Create a new size from a width and a height.
+    pub const fn new(width: Length, height: Length) -> Self {
+        Self { width, height }
+    }
+}
+
+/// Four values, one per side of a box.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Sides<T> {
+    pub left: T,
+    pub top: T,
+    pub right: T,
+    pub bottom: T,
+}
+
+/// A generic container with a value for the main and the cross axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gen<T> {
+    /// The value for the main axis.
+    pub main: T,
+    /// The value for the cross axis.
+    pub cross: T,
+}
+
+impl<T> Gen<T> {
+    /// Create a new instance from the two values.
+    pub const fn new(main: T, cross: T) -> Self {
+        Self { main, cross }
+    }
+}
+
+/// The two generic axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenAxis {
+    /// The axis that the content is flowing along.
+    Main,
+    /// The axis perpendicular to the flow.
+    Cross,
+}
+
+/// A direction of layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dir {
+    /// Left to right.
+    LTR,
+    /// Right to left.
+    RTL,
+    /// Top to bottom.
+    TTB,
+    /// Bottom to top.
+    BTT,
+}
+
+/// An alignment on an axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+    Top,
+    Bottom,
+}
@@ -0,0 +1,119 @@
+//! Newspaper-style multi-column layout.
+
+use crate::geom::{Align, Dir, Gen, Length, Linear, Size};
+
+use super::{AnyNode, StackChild, StackNode};
+
+/// Splits its child's main-axis content into `count` balanced columns of
+/// equal width, separated by `gutter`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnNode {
+    /// The number of columns.
+    pub count: usize,
+    /// The spacing between adjacent columns.
+    pub gutter: Linear,
+    /// The content to split across the columns.
+    pub child: AnyNode,
+}
+
+/// The geometry and content of a single laid-out column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    /// The size available to this column.
+    pub size: Size,
+    /// The content flowing in this column.
+    pub stack: StackNode,
+}
+
+impl ColumnNode {
+    /// Lay out the node into `self.count` columns that fit in `area`.
+    ///
+    /// The stack's children are balanced across the columns by count; the
+    /// region layout that later paginates each column's content is free to
+    /// move children between adjacent columns once their exact heights are
+    /// known.
+    pub fn layout(&self, area: Size) -> Vec<Column> {
+        let width = column_width(area.width, self.count, self.gutter);
+        let size = Size::new(width, area.height);
+
+        let (dirs, children) = match &self.child {
+            AnyNode::Stack(stack) => (stack.dirs, stack.children.clone()),
+            other => (
+                Gen::new(Dir::TTB, Dir::LTR),
+                vec![StackChild::Any(other.clone(), Gen::new(Align::Left, Align::Top), false)],
+            ),
+        };
+
+        balance(children, self.count)
+            .into_iter()
+            .map(|children| Column { size, stack: StackNode { dirs, children } })
+            .collect()
+    }
+}
+
+/// The width of a single column given the full available width, the column
+/// count and the gutter between columns.
+fn column_width(area_width: Length, count: usize, gutter: Linear) -> Length {
+    let gutter_width = gutter.resolve(area_width);
+    let gutters = gutter_width * (count.saturating_sub(1) as f64);
+    (area_width - gutters) / count as f64
+}
+
+/// Distribute `children` as evenly as possible across `count` columns,
+/// preserving their original order.
+fn balance(children: Vec<StackChild>, count: usize) -> Vec<Vec<StackChild>> {
+    let count = count.max(1);
+    let base = children.len() / count;
+    let extra = children.len() % count;
+
+    let mut children = children.into_iter();
+    (0..count)
+        .map(|i| children.by_ref().take(base + usize::from(i < extra)).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::{Align, Gen};
+
+    fn text_child() -> StackChild {
+        StackChild::Any(
+            AnyNode::Stack(Box::new(StackNode {
+                dirs: Gen::new(Dir::TTB, Dir::LTR),
+                children: vec![],
+            })),
+            Gen::new(Align::Left, Align::Top),
+            false,
+        )
+    }
+
+    #[test]
+    fn balances_children_across_columns() {
+        let children = vec![text_child(), text_child(), text_child()];
+        let columns = balance(children, 2);
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0].len() + columns[1].len(), 3);
+    }
+
+    #[test]
+    fn balance_pads_empty_columns_when_short_on_children() {
+        let columns = balance(vec![text_child()], 3);
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[0].len(), 1);
+        assert!(columns[1].is_empty());
+        assert!(columns[2].is_empty());
+    }
+
+    #[test]
+    fn column_width_accounts_for_gutters() {
+        let width = column_width(Length::pt(100.0), 2, Linear::abs(Length::pt(10.0)));
+        assert_eq!(width, Length::pt(45.0));
+    }
+
+    #[test]
+    fn single_column_keeps_full_width() {
+        let width = column_width(Length::pt(100.0), 1, Linear::abs(Length::pt(10.0)));
+        assert_eq!(width, Length::pt(100.0));
+    }
+}
@@ -0,0 +1,140 @@
+//! The layout tree produced by execution and consumed by the layout
+//! engine.
+
+mod columns;
+
+pub use columns::ColumnNode;
+
+use crate::geom::{Align, Dir, Gen, Length, Linear, Sides, Size};
+
+/// A finished document, ready to be laid out page by page.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Tree {
+    /// The finished page runs.
+    pub runs: Vec<PageRun>,
+}
+
+/// A single page of the document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRun {
+    /// The size of the page.
+    pub size: Size,
+    /// The content of the page.
+    pub child: AnyNode,
+}
+
+/// Resolved font properties for a run of text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontProps {
+    /// The resolved font size.
+    pub size: Length,
+    /// Whether the text is bold.
+    pub strong: bool,
+    /// Whether the text is italic.
+    pub emph: bool,
+}
+
+/// A padded node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PadNode {
+    /// The padding to apply on each side.
+    pub padding: Sides<Linear>,
+    /// The padded child.
+    pub child: AnyNode,
+}
+
+/// A node that stacks its children along an axis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StackNode {
+    /// The main and cross layouting directions.
+    pub dirs: Gen<Dir>,
+    /// The children to be stacked.
+    pub children: Vec<StackChild>,
+}
+
+/// A child of a [`StackNode`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum StackChild {
+    /// Spacing between other children.
+    Spacing(Length),
+    /// An arbitrary node, aligned within its cell. The trailing `bool` is
+    /// `keep_together`: when set, the page/region layout must place this
+    /// child as a whole rather than splitting it across a boundary.
+    Any(AnyNode, Gen<Align>, bool),
+}
+
+/// A node that arranges its children into a paragraph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParNode {
+    /// The text direction.
+    pub dir: Dir,
+    /// The spacing between lines.
+    pub line_spacing: Length,
+    /// The children of the paragraph.
+    pub children: Vec<ParChild>,
+}
+
+/// A child of a [`ParNode`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParChild {
+    /// A forced line break.
+    Linebreak,
+    /// Spacing between other children.
+    Spacing(Length),
+    /// A run of text.
+    Text(TextNode, Align),
+    /// An arbitrary inline node.
+    Any(AnyNode, Align),
+}
+
+/// A run of text with resolved properties.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextNode {
+    /// The text itself.
+    pub text: String,
+    /// The resolved font properties.
+    pub props: FontProps,
+    /// The resolved direction of this run, as determined by the Unicode
+    /// Bidirectional Algorithm.
+    pub dir: Dir,
+}
+
+/// Any node that can appear in the layout tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyNode {
+    Pad(Box<PadNode>),
+    Stack(Box<StackNode>),
+    Par(Box<ParNode>),
+    Text(Box<TextNode>),
+    Column(Box<ColumnNode>),
+}
+
+impl From<PadNode> for AnyNode {
+    fn from(node: PadNode) -> Self {
+        Self::Pad(Box::new(node))
+    }
+}
+
+impl From<StackNode> for AnyNode {
+    fn from(node: StackNode) -> Self {
+        Self::Stack(Box::new(node))
+    }
+}
+
+impl From<ParNode> for AnyNode {
+    fn from(node: ParNode) -> Self {
+        Self::Par(Box::new(node))
+    }
+}
+
+impl From<TextNode> for AnyNode {
+    fn from(node: TextNode) -> Self {
+        Self::Text(Box::new(node))
+    }
+}
+
+impl From<ColumnNode> for AnyNode {
+    fn from(node: ColumnNode) -> Self {
+        Self::Column(Box::new(node))
+    }
+}
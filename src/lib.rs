@@ -0,0 +1,13 @@
+//! Typst's compiler.
+
+#[macro_use]
+pub mod diag;
+
+pub mod env;
+pub mod eval;
+pub mod exec;
+pub mod geom;
+pub mod layout;
+pub mod markdown;
+pub mod parse;
+pub mod syntax;
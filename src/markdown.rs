@@ -0,0 +1,177 @@
+//! Ingestion of CommonMark Markdown source into the execution pipeline.
+
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag};
+
+use crate::exec::ExecContext;
+use crate::geom::Linear;
+
+/// Parse `source` as CommonMark and replay it onto `ctx`, mapping Markdown
+/// constructs onto the existing execution primitives so that the result
+/// goes through the regular layout tree.
+pub fn exec_markdown(ctx: &mut ExecContext, source: &str) {
+    let mut lists: Vec<Option<u64>> = vec![];
+    let mut sizes: Vec<Linear> = vec![];
+
+    for event in Parser::new_ext(source, Options::empty()) {
+        match event {
+            Event::Start(tag) => exec_start(ctx, &mut lists, &mut sizes, tag),
+            Event::End(tag) => exec_end(ctx, &mut lists, &mut sizes, tag),
+            Event::Text(text) => ctx.push_text(&text),
+            Event::Code(text) => {
+                ctx.set_monospace();
+                ctx.push_text(&text);
+                unset_monospace(ctx);
+            }
+            Event::SoftBreak => ctx.push_word_space(),
+            Event::HardBreak => ctx.linebreak(),
+            Event::Rule => ctx.parbreak(),
+            Event::Html(_) | Event::FootnoteReference(_) | Event::TaskListMarker(_) => {}
+        }
+    }
+}
+
+fn exec_start(
+    ctx: &mut ExecContext,
+    lists: &mut Vec<Option<u64>>,
+    sizes: &mut Vec<Linear>,
+    tag: Tag,
+) {
+    match tag {
+        Tag::BlockQuote => ctx.parbreak(),
+        Tag::Heading(level, _, _) => {
+            sizes.push(ctx.state.font.size);
+            ctx.state.font.size = Linear::rel(heading_scale(level));
+        }
+        Tag::CodeBlock(_) => {
+            ctx.parbreak();
+            ctx.set_monospace();
+        }
+        Tag::List(start) => lists.push(start),
+        Tag::Item => {
+            let prefix = match lists.last_mut() {
+                Some(Some(number)) => {
+                    let prefix = format!("{}. ", number);
+                    *number += 1;
+                    prefix
+                }
+                _ => "• ".to_string(),
+            };
+            ctx.push_text(&prefix);
+        }
+        Tag::Emphasis => ctx.state.font.emph = true,
+        Tag::Strong => ctx.state.font.strong = true,
+        Tag::Paragraph
+        | Tag::Strikethrough
+        | Tag::Link(..)
+        | Tag::Image(..)
+        | Tag::Table(_)
+        | Tag::TableHead
+        | Tag::TableRow
+        | Tag::TableCell
+        | Tag::FootnoteDefinition(_) => {}
+    }
+}
+
+fn exec_end(
+    ctx: &mut ExecContext,
+    lists: &mut Vec<Option<u64>>,
+    sizes: &mut Vec<Linear>,
+    tag: Tag,
+) {
+    match tag {
+        Tag::Paragraph | Tag::BlockQuote => ctx.parbreak(),
+        Tag::Heading(..) => {
+            ctx.parbreak();
+            if let Some(size) = sizes.pop() {
+                ctx.state.font.size = size;
+            }
+        }
+        Tag::CodeBlock(_) => {
+            unset_monospace(ctx);
+            ctx.parbreak();
+        }
+        Tag::List(_) => {
+            lists.pop();
+            ctx.parbreak();
+        }
+        Tag::Item => ctx.parbreak(),
+        Tag::Emphasis => ctx.state.font.emph = false,
+        Tag::Strong => ctx.state.font.strong = false,
+        Tag::Strikethrough
+        | Tag::Link(..)
+        | Tag::Image(..)
+        | Tag::Table(_)
+        | Tag::TableHead
+        | Tag::TableRow
+        | Tag::TableCell
+        | Tag::FootnoteDefinition(_) => {}
+    }
+}
+
+/// Undo the family list change made by `ExecContext::set_monospace`.
+fn unset_monospace(ctx: &mut ExecContext) {
+    ctx.state.font.families_mut().list.remove(0);
+}
+
+/// Relative font size for a Markdown heading level, largest at `H1`.
+fn heading_scale(level: HeadingLevel) -> f64 {
+    match level {
+        HeadingLevel::H1 => 1.6,
+        HeadingLevel::H2 => 1.4,
+        HeadingLevel::H3 => 1.2,
+        HeadingLevel::H4 => 1.1,
+        HeadingLevel::H5 | HeadingLevel::H6 => 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env::Env;
+    use crate::exec::State;
+    use crate::layout::{AnyNode, ParChild, StackChild};
+
+    #[test]
+    fn heading_and_emphasis_are_reverted_after_the_block_ends() {
+        let default = State::default();
+        let mut env = Env::new();
+        let mut ctx = ExecContext::new(&mut env, default.clone());
+
+        exec_markdown(&mut ctx, "# Title\n\nSome *text* and **more**.");
+
+        assert_eq!(ctx.state.font.size, default.font.size);
+        assert!(!ctx.state.font.emph);
+        assert!(!ctx.state.font.strong);
+    }
+
+    #[test]
+    fn ordered_list_items_get_increasing_number_prefixes() {
+        let mut env = Env::new();
+        let mut ctx = ExecContext::new(&mut env, State::default());
+
+        exec_markdown(&mut ctx, "1. one\n2. two\n3. three");
+
+        let tree = ctx.finish().output;
+        assert_eq!(tree.runs.len(), 1);
+
+        let AnyNode::Pad(pad) = &tree.runs[0].child else { panic!("expected a PadNode") };
+        let AnyNode::Stack(stack) = &pad.child else { panic!("expected a StackNode") };
+        let items: Vec<String> = stack
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                StackChild::Any(AnyNode::Par(par), ..) => Some(
+                    par.children
+                        .iter()
+                        .filter_map(|child| match child {
+                            ParChild::Text(node, _) => Some(node.text.as_str()),
+                            _ => None,
+                        })
+                        .collect(),
+                ),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(items, ["1. one", "2. two", "3. three"]);
+    }
+}
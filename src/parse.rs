@@ -0,0 +1,36 @@
+//! Low-level scanning helpers shared by the parser and other stages that
+//! need to walk source text character by character.
+
+use std::str::Chars;
+
+/// Whether a character is considered a newline for line-splitting purposes.
+pub fn is_newline(c: char) -> bool {
+    matches!(
+        c,
+        '\n' | '\r' | '\u{000B}' | '\u{000C}' | '\u{0085}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+/// A simple character-by-character scanner over a string.
+pub struct Scanner<'a> {
+    chars: Chars<'a>,
+}
+
+impl<'a> Scanner<'a> {
+    /// Create a new scanner over `text`.
+    pub fn new(text: &'a str) -> Self {
+        Self { chars: text.chars() }
+    }
+
+    /// Consume and return the next character, merging `\r\n` into a single
+    /// `\n`.
+    pub fn eat_merging_crlf(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\r' && self.chars.clone().next() == Some('\n') {
+            self.chars.next();
+            Some('\n')
+        } else {
+            Some(c)
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Source-level syntax types.
+
+/// A byte range into a source file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Span {
+    /// The inclusive start byte offset.
+    pub start: usize,
+    /// The exclusive end byte offset.
+    pub end: usize,
+}